@@ -0,0 +1,23 @@
+use crate::table::ColumnData;
+use crate::types::Schema;
+use std::collections::HashMap;
+
+// Columnar batch of results passed between plan nodes, paired with the
+// schema describing each column's native type.
+pub struct DataChunk {
+    pub schema: Schema,
+    pub columns: HashMap<String, ColumnData>,
+}
+
+impl DataChunk {
+    pub fn num_rows(&self) -> usize {
+        self.columns.values().next().map_or(0, |c| c.len())
+    }
+
+    // Renders a column as strings, for callers (tests, printing) that
+    // don't need the native representation.
+    pub fn strings(&self, col: &str) -> Vec<String> {
+        let column = &self.columns[col];
+        (0..column.len()).map(|i| column.to_string_at(i)).collect()
+    }
+}
@@ -1,29 +1,62 @@
 mod table;
 mod plan;
+mod sql;
 mod execution;
 mod types;
 mod util;
 
+use crate::plan::Executable;
+use crate::sql::{parse_sql_to_plan, ParsedStatement};
 use crate::table::Table;
+use std::collections::HashMap;
+use std::path::Path;
 
 fn main() {
     println!("OLAP Engine Initialized.");
 
-    let path = "data/sample.csv";
+    let mut args = std::env::args().skip(1);
+    let path = args.next().unwrap_or_else(|| "data/sample.csv".to_string());
+    let query = args.next();
 
-    match Table::load_csv(path) {
-        Ok(table) => {
+    let table = match Table::load_csv(&path) {
+        Ok(table) => table,
+        Err(e) => {
+            eprintln!("Failed to load CSV: {}", e);
+            return;
+        }
+    };
+
+    let query = match query {
+        Some(query) => query,
+        None => {
             for (col_name, column) in &table.columns {
-                println!("Column: {}", col_name);
-                for value in &column.data {
-                    println!("  {}", value);
+                println!("Column: {} ({:?})", col_name, column.data_type());
+                for i in 0..column.len() {
+                    println!("  {}", column.to_string_at(i));
                 }
             }
+            return;
         }
-        Err(e) => {
-            eprintln!("Failed to load CSV: {}", e);
+    };
+
+    // The table is addressed in FROM clauses by the CSV's file stem, e.g.
+    // `data/sample.csv` is queried as `FROM sample`.
+    let table_name = Path::new(&path)
+        .file_stem()
+        .and_then(|s| s.to_str())
+        .unwrap_or(&path)
+        .to_string();
+
+    let mut tables = HashMap::new();
+    tables.insert(table_name, table);
+
+    match parse_sql_to_plan(&query, &tables) {
+        ParsedStatement::Explained(plan_tree) => println!("{}", plan_tree),
+        ParsedStatement::Plan(plan) => {
+            let chunk = plan.execute();
+            for col in chunk.schema.keys() {
+                println!("{}: {:?}", col, chunk.strings(col));
+            }
         }
     }
-
-    // TODO: accept CLI args to load CSV and run queries
 }
@@ -1,18 +1,125 @@
 use crate::execution::DataChunk;
-use crate::table::Table;
-use std::collections::HashMap;
+use crate::table::{ColumnData, Table};
+use crate::types::{DataType, Schema, Value};
+use crate::util::compare_str;
+use std::collections::{HashMap, HashSet};
+use std::rc::Rc;
 
 // Trait for executable plan nodes
 pub trait Executable {
     fn execute(&self) -> DataChunk;
 }
 
+// Positional schema threaded through a `RowStream`: column names and
+// their native types, in the same order as each `Row`'s `values`.
+pub struct RowSchema {
+    columns: Vec<(String, DataType)>,
+}
+
+impl RowSchema {
+    fn new(columns: Vec<(String, DataType)>) -> Self {
+        RowSchema { columns }
+    }
+
+    fn index_of(&self, name: &str) -> usize {
+        self.columns
+            .iter()
+            .position(|(col, _)| col == name)
+            .unwrap_or_else(|| panic!("no column named '{}' in row schema", name))
+    }
+}
+
+// A lightweight positional row pulled from a `RowStream`: `values[i]`
+// corresponds to `schema`'s i-th column.
+pub struct Row {
+    pub schema: Rc<RowSchema>,
+    pub values: Vec<Value>,
+}
+
+impl Row {
+    pub fn get(&self, name: &str) -> &Value {
+        &self.values[self.schema.index_of(name)]
+    }
+}
+
+// Pull-based (Volcano-style) row source: a consumer calls `next` one row
+// at a time, so it can stop pulling early (e.g. `LimitNode`) without the
+// producer ever materializing the rest of its input.
+pub trait RowStream {
+    fn schema(&self) -> &Rc<RowSchema>;
+    fn next(&mut self) -> Option<Row>;
+}
+
+// Drains a `RowStream` back into a columnar `DataChunk`. Thin adapter
+// kept around for callers (tests, `AggregateNode`/`SortNode`'s own
+// pipeline-breaking inputs) that still want the batch-oriented shape.
+fn collect(mut stream: Box<dyn RowStream + '_>) -> DataChunk {
+    let row_schema = Rc::clone(stream.schema());
+
+    let mut schema = Schema::new();
+    let mut columns: HashMap<String, ColumnData> = HashMap::new();
+    for (name, dtype) in &row_schema.columns {
+        schema.insert(name.clone(), *dtype);
+        columns.insert(name.clone(), empty_column_of(*dtype));
+    }
+
+    while let Some(row) = stream.next() {
+        for (i, (name, _)) in row_schema.columns.iter().enumerate() {
+            columns.get_mut(name).unwrap().push_value(&row.values[i]);
+        }
+    }
+
+    DataChunk { schema, columns }
+}
+
+// Streams a materialized `DataChunk` back out row-by-row. Used to give
+// pipeline-breaking nodes (`AggregateNode`, `JoinNode`, `SortNode`) a
+// `RowStream` front end without teaching them to produce rows directly.
+struct ChunkStream {
+    schema: Rc<RowSchema>,
+    chunk: DataChunk,
+    idx: usize,
+    len: usize,
+}
+
+impl ChunkStream {
+    fn new(chunk: DataChunk) -> Self {
+        let columns: Vec<(String, DataType)> =
+            chunk.schema.iter().map(|(name, dtype)| (name.clone(), *dtype)).collect();
+        let len = chunk.num_rows();
+        ChunkStream { schema: Rc::new(RowSchema::new(columns)), chunk, idx: 0, len }
+    }
+}
+
+impl RowStream for ChunkStream {
+    fn schema(&self) -> &Rc<RowSchema> {
+        &self.schema
+    }
+
+    fn next(&mut self) -> Option<Row> {
+        if self.idx >= self.len {
+            return None;
+        }
+        let values = self
+            .schema
+            .columns
+            .iter()
+            .map(|(name, _)| self.chunk.columns[name].value_at(self.idx))
+            .collect();
+        self.idx += 1;
+        Some(Row { schema: Rc::clone(&self.schema), values })
+    }
+}
+
 // Logical plan node enum
 pub enum PlanNode<'a> {
     Scan(ScanNode<'a>),
     Project(ProjectNode<'a>),
     Filter(FilterNode<'a>),
     Aggregate(AggregateNode<'a>),
+    Join(JoinNode<'a>),
+    Sort(SortNode<'a>),
+    Limit(LimitNode<'a>),
 }
 
 impl<'a> Executable for PlanNode<'a> {
@@ -22,6 +129,143 @@ impl<'a> Executable for PlanNode<'a> {
             PlanNode::Project(proj) => proj.execute(),
             PlanNode::Filter(filt) => filt.execute(),
             PlanNode::Aggregate(agg) => agg.execute(),
+            PlanNode::Join(join) => join.execute(),
+            PlanNode::Sort(sort) => sort.execute(),
+            PlanNode::Limit(limit) => limit.execute(),
+        }
+    }
+}
+
+impl<'a> PlanNode<'a> {
+    // Computes the schema this node's output would have without running
+    // any of the data-path logic. Used for plan-build-time validation
+    // (e.g. rejecting `sum(name)` before a single row is scanned).
+    pub fn output_schema(&self) -> Schema {
+        match self {
+            PlanNode::Scan(scan) => scan.table.schema.clone(),
+            PlanNode::Project(proj) => {
+                let input_schema = proj.input.output_schema();
+                input_schema
+                    .into_iter()
+                    .filter(|(name, _)| proj.columns.contains(name))
+                    .collect()
+            }
+            PlanNode::Filter(filt) => filt.input.output_schema(),
+            PlanNode::Aggregate(agg) => {
+                let input_schema = agg.input.output_schema();
+                let mut schema = Schema::new();
+                for col in &agg.group_by {
+                    schema.insert(col.clone(), input_schema[col]);
+                }
+                for (col, func) in &agg.aggregates {
+                    schema.insert(col.clone(), agg.output_type(*func, &input_schema, col));
+                }
+                schema
+            }
+            PlanNode::Join(join) => {
+                let left_schema = join.left.output_schema();
+                let right_schema = join.right.output_schema();
+                let left_names: HashSet<&String> = left_schema.keys().collect();
+                let right_names: HashSet<&String> = right_schema.keys().collect();
+
+                let mut schema = Schema::new();
+                for (name, dtype) in &left_schema {
+                    let out_name = join.output_name(&join.left_name, name, right_names.contains(name));
+                    schema.insert(out_name, *dtype);
+                }
+                for (name, dtype) in &right_schema {
+                    let out_name = join.output_name(&join.right_name, name, left_names.contains(name));
+                    schema.insert(out_name, *dtype);
+                }
+                schema
+            }
+            PlanNode::Sort(sort) => sort.input.output_schema(),
+            PlanNode::Limit(limit) => limit.input.output_schema(),
+        }
+    }
+
+    // Renders this node and its inputs as an indented tree, e.g.:
+    //   Aggregate: group_by=[region], aggs=[sum(sales)]
+    //     Filter: region = 'East'
+    //       Scan: orders
+    pub fn explain(&self, indent: usize) -> String {
+        let prefix = "  ".repeat(indent);
+        match self {
+            PlanNode::Scan(scan) => format!("{}Scan: {}", prefix, scan.name),
+            PlanNode::Project(proj) => format!(
+                "{}Project: columns=[{}]\n{}",
+                prefix,
+                proj.columns.join(", "),
+                proj.input.explain(indent + 1)
+            ),
+            PlanNode::Filter(filt) => format!(
+                "{}Filter: {}\n{}",
+                prefix,
+                filt.predicate.to_sql(),
+                filt.input.explain(indent + 1)
+            ),
+            PlanNode::Aggregate(agg) => {
+                let aggs: Vec<String> = agg
+                    .aggregates
+                    .iter()
+                    .map(|(col, func)| format!("{}({})", func.as_sql(), col))
+                    .collect();
+                format!(
+                    "{}Aggregate: group_by=[{}], aggs=[{}]\n{}",
+                    prefix,
+                    agg.group_by.join(", "),
+                    aggs.join(", "),
+                    agg.input.explain(indent + 1)
+                )
+            }
+            PlanNode::Join(join) => format!(
+                "{}Join: type={:?}, on={}.{}={}.{}\n{}\n{}",
+                prefix,
+                join.join_type,
+                join.left_name,
+                join.left_on,
+                join.right_name,
+                join.right_on,
+                join.left.explain(indent + 1),
+                join.right.explain(indent + 1)
+            ),
+            PlanNode::Sort(sort) => {
+                let keys: Vec<String> = sort
+                    .sort_keys
+                    .iter()
+                    .map(|(col, desc)| format!("{} {}", col, if *desc { "DESC" } else { "ASC" }))
+                    .collect();
+                format!(
+                    "{}Sort: keys=[{}]\n{}",
+                    prefix,
+                    keys.join(", "),
+                    sort.input.explain(indent + 1)
+                )
+            }
+            PlanNode::Limit(limit) => format!(
+                "{}Limit: limit={}, offset={}\n{}",
+                prefix,
+                limit.limit.map(|l| l.to_string()).unwrap_or_else(|| "None".to_string()),
+                limit.offset,
+                limit.input.explain(indent + 1)
+            ),
+        }
+    }
+
+    // Opens a lazy, pull-based `RowStream` over this node's output.
+    // `Scan`/`Project`/`Filter`/`Limit` stream rows one at a time from
+    // their input; `Aggregate`/`Join`/`Sort` are pipeline breakers that
+    // drain their child once (via their existing `execute`) and stream
+    // out of the resulting `DataChunk`.
+    pub fn open(&self) -> Box<dyn RowStream + 'a> {
+        match self {
+            PlanNode::Scan(scan) => scan.open(),
+            PlanNode::Project(proj) => proj.open(),
+            PlanNode::Filter(filt) => filt.open(),
+            PlanNode::Aggregate(agg) => Box::new(ChunkStream::new(agg.execute())),
+            PlanNode::Join(join) => Box::new(ChunkStream::new(join.execute())),
+            PlanNode::Sort(sort) => Box::new(ChunkStream::new(sort.execute())),
+            PlanNode::Limit(limit) => limit.open(),
         }
     }
 }
@@ -29,15 +273,62 @@ impl<'a> Executable for PlanNode<'a> {
 // Plan Node: Scan (reads full table)
 pub struct ScanNode<'a> {
     pub table: &'a Table,
+    // The table's name (or FROM-clause alias), kept around purely for
+    // `PlanNode::explain`.
+    pub name: String,
+}
+
+impl<'a> ScanNode<'a> {
+    fn open(&self) -> Box<dyn RowStream + 'a> {
+        Box::new(ScanStream::new(self.table))
+    }
 }
 
 impl<'a> Executable for ScanNode<'a> {
     fn execute(&self) -> DataChunk {
-        self.table
+        collect(self.open())
+    }
+}
+
+// Pulls rows lazily from a `Table`, one row at a time, reading each
+// column by name.
+struct ScanStream<'a> {
+    table: &'a Table,
+    schema: Rc<RowSchema>,
+    idx: usize,
+    len: usize,
+}
+
+impl<'a> ScanStream<'a> {
+    fn new(table: &'a Table) -> Self {
+        let columns: Vec<(String, DataType)> =
+            table.schema.iter().map(|(name, dtype)| (name.clone(), *dtype)).collect();
+        ScanStream {
+            table,
+            schema: Rc::new(RowSchema::new(columns)),
+            idx: 0,
+            len: table.num_rows(),
+        }
+    }
+}
+
+impl<'a> RowStream for ScanStream<'a> {
+    fn schema(&self) -> &Rc<RowSchema> {
+        &self.schema
+    }
+
+    fn next(&mut self) -> Option<Row> {
+        if self.idx >= self.len {
+            return None;
+        }
+        let values = self
+            .schema
             .columns
             .iter()
-            .map(|(name, col)| (name.clone(), col.data.clone()))
-            .collect()
+            .map(|(name, _)| self.table.columns[name].value_at(self.idx))
+            .collect();
+        self.idx += 1;
+        Some(Row { schema: Rc::clone(&self.schema), values })
     }
 }
 
@@ -47,54 +338,274 @@ pub struct ProjectNode<'a> {
     pub columns: Vec<String>,
 }
 
+impl<'a> ProjectNode<'a> {
+    fn open(&self) -> Box<dyn RowStream + 'a> {
+        Box::new(ProjectStream::new(self.input.open(), &self.columns))
+    }
+}
+
 impl<'a> Executable for ProjectNode<'a> {
     fn execute(&self) -> DataChunk {
-        let input_chunk = self.input.execute();
-        input_chunk
-            .into_iter()
-            .filter(|(col, _)| self.columns.contains(col))
-            .collect()
+        collect(self.open())
+    }
+}
+
+// Drops columns by index as rows are pulled from `input`.
+struct ProjectStream<'a> {
+    input: Box<dyn RowStream + 'a>,
+    schema: Rc<RowSchema>,
+    indices: Vec<usize>,
+}
+
+impl<'a> ProjectStream<'a> {
+    fn new(input: Box<dyn RowStream + 'a>, columns: &[String]) -> Self {
+        let input_schema = input.schema();
+        let indices: Vec<usize> = columns.iter().map(|col| input_schema.index_of(col)).collect();
+        let schema = Rc::new(RowSchema::new(
+            indices.iter().map(|&i| input_schema.columns[i].clone()).collect(),
+        ));
+        ProjectStream { input, schema, indices }
+    }
+}
+
+impl<'a> RowStream for ProjectStream<'a> {
+    fn schema(&self) -> &Rc<RowSchema> {
+        &self.schema
+    }
+
+    fn next(&mut self) -> Option<Row> {
+        let row = self.input.next()?;
+        let values = self.indices.iter().map(|&i| row.values[i].clone()).collect();
+        Some(Row { schema: Rc::clone(&self.schema), values })
+    }
+}
+
+// Comparison and boolean operators supported by `Expr::BinaryOp`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Op {
+    Eq,
+    NotEq,
+    Lt,
+    LtEq,
+    Gt,
+    GtEq,
+    And,
+    Or,
+}
+
+// General expression tree built from a `sqlparser` WHERE clause.
+#[derive(Debug, Clone)]
+pub enum Expr {
+    Column(String),
+    Literal(Value),
+    BinaryOp {
+        left: Box<Expr>,
+        op: Op,
+        right: Box<Expr>,
+    },
+    Not(Box<Expr>),
+}
+
+impl Op {
+    // The SQL spelling of this operator, used by `Expr::to_sql` for EXPLAIN.
+    fn as_sql(&self) -> &'static str {
+        match self {
+            Op::Eq => "=",
+            Op::NotEq => "<>",
+            Op::Lt => "<",
+            Op::LtEq => "<=",
+            Op::Gt => ">",
+            Op::GtEq => ">=",
+            Op::And => "AND",
+            Op::Or => "OR",
+        }
+    }
+}
+
+impl Expr {
+    // Evaluates this expression against a row's positional values,
+    // looking columns up by name via the row's schema.
+    pub fn evaluate(&self, row: &Row) -> Value {
+        match self {
+            Expr::Column(name) => row.get(name).clone(),
+            Expr::Literal(val) => val.clone(),
+            Expr::Not(inner) => Value::Bool(!inner.evaluate(row).as_bool()),
+            Expr::BinaryOp { left, op, right } => match op {
+                Op::And => Value::Bool(left.evaluate(row).as_bool() && right.evaluate(row).as_bool()),
+                Op::Or => Value::Bool(left.evaluate(row).as_bool() || right.evaluate(row).as_bool()),
+                _ => {
+                    let l = left.evaluate(row).to_comparable_string();
+                    let r = right.evaluate(row).to_comparable_string();
+                    let ordering = compare_str(&l, &r);
+                    let result = match op {
+                        Op::Eq => ordering == std::cmp::Ordering::Equal,
+                        Op::NotEq => ordering != std::cmp::Ordering::Equal,
+                        Op::Lt => ordering == std::cmp::Ordering::Less,
+                        Op::LtEq => ordering != std::cmp::Ordering::Greater,
+                        Op::Gt => ordering == std::cmp::Ordering::Greater,
+                        Op::GtEq => ordering != std::cmp::Ordering::Less,
+                        Op::And | Op::Or => unreachable!(),
+                    };
+                    Value::Bool(result)
+                }
+            },
+        }
+    }
+
+    // Renders this expression back to roughly the SQL that produced it,
+    // for `EXPLAIN` output.
+    fn to_sql(&self) -> String {
+        match self {
+            Expr::Column(name) => name.clone(),
+            Expr::Literal(Value::Str(s)) => format!("'{}'", s),
+            Expr::Literal(val) => val.to_comparable_string(),
+            Expr::Not(inner) => format!("NOT {}", inner.to_sql()),
+            Expr::BinaryOp { left, op, right } => {
+                format!("{} {} {}", left.to_sql(), op.as_sql(), right.to_sql())
+            }
+        }
     }
 }
 
 // Plan Node: Filter (apply condition to rows)
 pub struct FilterNode<'a> {
     pub input: Box<PlanNode<'a>>,
-    pub predicate: Box<dyn Fn(&HashMap<String, String>) -> bool + 'a>,
+    pub predicate: Expr,
+}
+
+impl<'a> FilterNode<'a> {
+    fn open(&self) -> Box<dyn RowStream + 'a> {
+        Box::new(FilterStream {
+            input: self.input.open(),
+            predicate: self.predicate.clone(),
+        })
+    }
 }
 
 impl<'a> Executable for FilterNode<'a> {
     fn execute(&self) -> DataChunk {
-        let input_chunk = self.input.execute();
-        let num_rows = input_chunk.values().next().map_or(0, |v| v.len());
+        collect(self.open())
+    }
+}
 
-        // Construct row-wise view to apply predicate
-        let mut results: HashMap<String, Vec<String>> = input_chunk
-            .keys()
-            .map(|col| (col.clone(), Vec::new()))
-            .collect();
+// Forwards only the rows from `input` that pass `predicate`.
+struct FilterStream<'a> {
+    input: Box<dyn RowStream + 'a>,
+    predicate: Expr,
+}
 
-        for i in 0..num_rows {
-            let row: HashMap<String, String> = input_chunk
-                .iter()
-                .map(|(k, v)| (k.clone(), v[i].clone()))
-                .collect();
+impl<'a> RowStream for FilterStream<'a> {
+    fn schema(&self) -> &Rc<RowSchema> {
+        self.input.schema()
+    }
 
-            if (self.predicate)(&row) {
-                for (col, vec) in &mut results {
-                    vec.push(input_chunk[col][i].clone());
-                }
+    fn next(&mut self) -> Option<Row> {
+        loop {
+            let row = self.input.next()?;
+            if self.predicate.evaluate(&row).as_bool() {
+                return Some(row);
             }
         }
-
-        results
     }
 }
 
 // Aggregate Function Enum
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum AggregateFunction {
   Count,
   Sum,
+  Avg,
+  Min,
+  Max,
+}
+
+impl AggregateFunction {
+    // `Sum`/`Avg` need a column they can actually add together; `Count`
+    // works on anything, and `Min`/`Max` work on any orderable column
+    // (handled lexically-or-numerically by `compare_str`).
+    fn requires_numeric_column(&self) -> bool {
+        matches!(self, AggregateFunction::Sum | AggregateFunction::Avg)
+    }
+
+    // The SQL function name, used by `AggregateNode::explain`.
+    fn as_sql(&self) -> &'static str {
+        match self {
+            AggregateFunction::Count => "count",
+            AggregateFunction::Sum => "sum",
+            AggregateFunction::Avg => "avg",
+            AggregateFunction::Min => "min",
+            AggregateFunction::Max => "max",
+        }
+    }
+}
+
+// Per-group accumulator state for a single aggregate.
+enum Accumulator {
+    Count(u64),
+    Sum(f64),
+    Avg { sum: f64, count: u64 },
+    Min(Option<String>),
+    Max(Option<String>),
+}
+
+impl Accumulator {
+    fn new(func: AggregateFunction) -> Self {
+        match func {
+            AggregateFunction::Count => Accumulator::Count(0),
+            AggregateFunction::Sum => Accumulator::Sum(0.0),
+            AggregateFunction::Avg => Accumulator::Avg { sum: 0.0, count: 0 },
+            AggregateFunction::Min => Accumulator::Min(None),
+            AggregateFunction::Max => Accumulator::Max(None),
+        }
+    }
+
+    fn update(&mut self, value: &str) {
+        match self {
+            Accumulator::Count(n) => *n += 1,
+            Accumulator::Sum(total) => {
+                if let Ok(v) = value.parse::<f64>() {
+                    *total += v;
+                }
+            }
+            Accumulator::Avg { sum, count } => {
+                if let Ok(v) = value.parse::<f64>() {
+                    *sum += v;
+                    *count += 1;
+                }
+            }
+            Accumulator::Min(current) => {
+                if value.is_empty() {
+                    return;
+                }
+                if current.as_deref().map_or(true, |c| compare_str(value, c) == std::cmp::Ordering::Less) {
+                    *current = Some(value.to_string());
+                }
+            }
+            Accumulator::Max(current) => {
+                if value.is_empty() {
+                    return;
+                }
+                if current.as_deref().map_or(true, |c| compare_str(value, c) == std::cmp::Ordering::Greater) {
+                    *current = Some(value.to_string());
+                }
+            }
+        }
+    }
+
+    fn finalize(&self) -> String {
+        match self {
+            Accumulator::Count(n) => n.to_string(),
+            Accumulator::Sum(total) => total.to_string(),
+            Accumulator::Avg { sum, count } => {
+                if *count == 0 {
+                    String::new()
+                } else {
+                    (sum / *count as f64).to_string()
+                }
+            }
+            Accumulator::Min(current) | Accumulator::Max(current) => current.clone().unwrap_or_default(),
+        }
+    }
 }
 
 // Plan Node: Aggregate (group by + aggregation)
@@ -104,166 +615,581 @@ pub struct AggregateNode<'a> {
   pub aggregates: Vec<(String, AggregateFunction)>,
 }
 
+impl<'a> AggregateNode<'a> {
+    // Validates that every group-by/aggregate column exists and that
+    // every aggregate is applicable to its column's type before any
+    // execution happens, mirroring "cannot apply aggregate to these
+    // types" planner errors rather than panicking on a missing-column
+    // index at execute time.
+    pub fn try_new(
+        input: Box<PlanNode<'a>>,
+        group_by: Vec<String>,
+        aggregates: Vec<(String, AggregateFunction)>,
+    ) -> Result<Self, String> {
+        let input_schema = input.output_schema();
+
+        for col in &group_by {
+            if !input_schema.contains_key(col) {
+                return Err(format!("no column named '{}' to group by", col));
+            }
+        }
+
+        for (col, func) in &aggregates {
+            let dtype = input_schema
+                .get(col)
+                .ok_or_else(|| format!("no column named '{}' to aggregate", col))?;
+
+            if func.requires_numeric_column() {
+                let numeric = matches!(dtype, DataType::Int64 | DataType::Float64);
+                if !numeric {
+                    return Err(format!(
+                        "cannot apply {:?} to non-numeric column '{}'",
+                        func, col
+                    ));
+                }
+            }
+        }
+
+        Ok(AggregateNode { input, group_by, aggregates })
+    }
+
+    // The dtype an aggregate's output column carries: `Min`/`Max` preserve
+    // the input column's type, everything else produces a `Float64`.
+    fn output_type(&self, func: AggregateFunction, input_schema: &Schema, col: &str) -> DataType {
+        match func {
+            AggregateFunction::Min | AggregateFunction::Max => input_schema[col],
+            AggregateFunction::Count | AggregateFunction::Sum | AggregateFunction::Avg => DataType::Float64,
+        }
+    }
+}
+
 impl<'a> Executable for AggregateNode<'a> {
   fn execute(&self) -> DataChunk {
       let input_chunk = self.input.execute();
-      let num_rows = input_chunk.values().next().map_or(0, |v| v.len());
+      let num_rows = input_chunk.num_rows();
 
-      let mut groups: HashMap<Vec<String>, HashMap<String, f64>> = HashMap::new();
+      let mut groups: HashMap<Vec<String>, HashMap<String, Accumulator>> = HashMap::new();
 
       for i in 0..num_rows {
           let group_key: Vec<String> = self
               .group_by
               .iter()
-              .map(|col| input_chunk[col][i].clone())
+              .map(|col| input_chunk.columns[col].to_string_at(i))
               .collect();
 
           let entry = groups.entry(group_key).or_insert_with(|| {
-              let mut init = HashMap::new();
-              for (col, func) in &self.aggregates {
-                  match func {
-                      AggregateFunction::Count => {
-                          init.insert(col.clone(), 0.0);
-                      }
-                      AggregateFunction::Sum => {
-                          init.insert(col.clone(), 0.0);
-                      }
-                  }
-              }
-              init
+              self.aggregates
+                  .iter()
+                  .map(|(col, func)| (col.clone(), Accumulator::new(*func)))
+                  .collect()
           });
 
-          for (col, func) in &self.aggregates {
-              let val = &input_chunk[col][i];
-              match func {
-                  AggregateFunction::Count => {
-                      *entry.get_mut(col).unwrap() += 1.0;
-                  }
-                  AggregateFunction::Sum => {
-                      if let Ok(v) = val.parse::<f64>() {
-                          *entry.get_mut(col).unwrap() += v;
-                      }
-                  }
-              }
+          for (col, _func) in &self.aggregates {
+              let val = input_chunk.columns[col].to_string_at(i);
+              entry.get_mut(col).unwrap().update(&val);
           }
       }
 
-      let mut result: DataChunk = HashMap::new();
+      let mut schema = Schema::new();
+      let mut result: HashMap<String, ColumnData> = HashMap::new();
 
       for col in &self.group_by {
-          result.insert(col.clone(), Vec::new());
+          schema.insert(col.clone(), input_chunk.schema[col]);
+          result.insert(col.clone(), input_chunk.columns[col].empty_like());
       }
-      for (col, _) in &self.aggregates {
-          result.insert(col.clone(), Vec::new());
+      for (col, func) in &self.aggregates {
+          let dtype = self.output_type(*func, &input_chunk.schema, col);
+          schema.insert(col.clone(), dtype);
+          result.insert(col.clone(), empty_column_of(dtype));
       }
 
-      for (key, agg_vals) in groups {
+      for (key, accs) in groups {
           for (i, col) in self.group_by.iter().enumerate() {
-              result.get_mut(col).unwrap().push(key[i].clone());
+              result.get_mut(col).unwrap().push_str(&key[i]);
           }
-          for (col, val) in agg_vals {
-              result.get_mut(&col).unwrap().push(val.to_string());
+          for (col, acc) in accs {
+              result.get_mut(&col).unwrap().push_str(&acc.finalize());
           }
       }
 
-      result
+      DataChunk { schema, columns: result }
   }
 }
 
+fn empty_column_of(data_type: DataType) -> ColumnData {
+    match data_type {
+        DataType::Int64 => ColumnData::Int64(Vec::new()),
+        DataType::Float64 => ColumnData::Float64(Vec::new()),
+        DataType::Boolean => ColumnData::Boolean(Vec::new()),
+        DataType::Utf8 => ColumnData::Utf8(Vec::new()),
+    }
+}
+
+// Join Type Enum
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JoinType {
+    Inner,
+    LeftOuter,
+}
+
+// Plan Node: Join (hash join of two inputs on equality keys)
+pub struct JoinNode<'a> {
+    pub left: Box<PlanNode<'a>>,
+    pub right: Box<PlanNode<'a>>,
+    pub left_on: String,
+    pub right_on: String,
+    // Aliases used to disambiguate column names that collide between
+    // `left` and `right`, e.g. `a.id` / `b.id`.
+    pub left_name: String,
+    pub right_name: String,
+    pub join_type: JoinType,
+}
+
+impl<'a> JoinNode<'a> {
+    fn output_name(&self, side: &str, col: &str, collides: bool) -> String {
+        if collides {
+            format!("{}.{}", side, col)
+        } else {
+            col.to_string()
+        }
+    }
+}
+
+impl<'a> Executable for JoinNode<'a> {
+    fn execute(&self) -> DataChunk {
+        let left_chunk = self.left.execute();
+        let right_chunk = self.right.execute();
+
+        let left_names: HashSet<&String> = left_chunk.columns.keys().collect();
+        let right_names: HashSet<&String> = right_chunk.columns.keys().collect();
+
+        let mut schema = Schema::new();
+        let mut columns: HashMap<String, ColumnData> = HashMap::new();
+
+        for (name, data) in &left_chunk.columns {
+            let out_name = self.output_name(&self.left_name, name, right_names.contains(name));
+            schema.insert(out_name.clone(), left_chunk.schema[name]);
+            columns.insert(out_name, data.empty_like());
+        }
+        for (name, data) in &right_chunk.columns {
+            let out_name = self.output_name(&self.right_name, name, left_names.contains(name));
+            schema.insert(out_name.clone(), right_chunk.schema[name]);
+            columns.insert(out_name, data.empty_like());
+        }
+
+        // Build side: materialize the right input keyed by its join column.
+        let right_key_col = &right_chunk.columns[&self.right_on];
+        let mut build_index: HashMap<String, Vec<usize>> = HashMap::new();
+        for j in 0..right_chunk.num_rows() {
+            build_index
+                .entry(right_key_col.to_string_at(j))
+                .or_default()
+                .push(j);
+        }
+
+        // Probe side: stream the left input and look up matches.
+        let left_key_col = &left_chunk.columns[&self.left_on];
+        for i in 0..left_chunk.num_rows() {
+            let key = left_key_col.to_string_at(i);
+
+            match build_index.get(&key) {
+                Some(matches) => {
+                    for &j in matches {
+                        for (name, data) in &left_chunk.columns {
+                            let out_name =
+                                self.output_name(&self.left_name, name, right_names.contains(name));
+                            columns.get_mut(&out_name).unwrap().push_from(data, i);
+                        }
+                        for (name, data) in &right_chunk.columns {
+                            let out_name =
+                                self.output_name(&self.right_name, name, left_names.contains(name));
+                            columns.get_mut(&out_name).unwrap().push_from(data, j);
+                        }
+                    }
+                }
+                None => {
+                    if self.join_type == JoinType::LeftOuter {
+                        for (name, data) in &left_chunk.columns {
+                            let out_name =
+                                self.output_name(&self.left_name, name, right_names.contains(name));
+                            columns.get_mut(&out_name).unwrap().push_from(data, i);
+                        }
+                        for name in right_chunk.columns.keys() {
+                            let out_name =
+                                self.output_name(&self.right_name, name, left_names.contains(name));
+                            columns.get_mut(&out_name).unwrap().push_null();
+                        }
+                    }
+                }
+            }
+        }
+
+        DataChunk { schema, columns }
+    }
+}
+
+// Plan Node: Sort (order rows by one or more keys)
+pub struct SortNode<'a> {
+    pub input: Box<PlanNode<'a>>,
+    // (column, descending)
+    pub sort_keys: Vec<(String, bool)>,
+}
+
+impl<'a> SortNode<'a> {
+    // Validates that every sort key exists in the input before any
+    // execution happens, rather than panicking on a missing-column index
+    // at execute time.
+    pub fn try_new(input: Box<PlanNode<'a>>, sort_keys: Vec<(String, bool)>) -> Result<Self, String> {
+        let input_schema = input.output_schema();
+
+        for (col, _) in &sort_keys {
+            if !input_schema.contains_key(col) {
+                return Err(format!("no column named '{}' to sort by", col));
+            }
+        }
+
+        Ok(SortNode { input, sort_keys })
+    }
+}
+
+impl<'a> Executable for SortNode<'a> {
+    fn execute(&self) -> DataChunk {
+        let input_chunk = self.input.execute();
+        let num_rows = input_chunk.num_rows();
+
+        let mut indices: Vec<usize> = (0..num_rows).collect();
+        indices.sort_by(|&a, &b| {
+            for (col, descending) in &self.sort_keys {
+                let col_data = &input_chunk.columns[col];
+                let mut ordering = compare_str(&col_data.to_string_at(a), &col_data.to_string_at(b));
+                if *descending {
+                    ordering = ordering.reverse();
+                }
+                if ordering != std::cmp::Ordering::Equal {
+                    return ordering;
+                }
+            }
+            std::cmp::Ordering::Equal
+        });
+
+        let columns = input_chunk
+            .columns
+            .iter()
+            .map(|(name, data)| (name.clone(), data.gather(&indices)))
+            .collect();
+
+        DataChunk {
+            schema: input_chunk.schema.clone(),
+            columns,
+        }
+    }
+}
+
+// Plan Node: Limit (cap the number of rows, with an optional offset)
+pub struct LimitNode<'a> {
+    pub input: Box<PlanNode<'a>>,
+    pub limit: Option<usize>,
+    pub offset: usize,
+}
+
+impl<'a> LimitNode<'a> {
+    fn open(&self) -> Box<dyn RowStream + 'a> {
+        Box::new(LimitStream {
+            input: self.input.open(),
+            remaining_offset: self.offset,
+            remaining_limit: self.limit,
+        })
+    }
+}
+
+impl<'a> Executable for LimitNode<'a> {
+    fn execute(&self) -> DataChunk {
+        collect(self.open())
+    }
+}
+
+// Skips `remaining_offset` rows, then forwards up to `remaining_limit`
+// more before stopping — without ever pulling the rest of `input`.
+struct LimitStream<'a> {
+    input: Box<dyn RowStream + 'a>,
+    remaining_offset: usize,
+    remaining_limit: Option<usize>,
+}
+
+impl<'a> RowStream for LimitStream<'a> {
+    fn schema(&self) -> &Rc<RowSchema> {
+        self.input.schema()
+    }
+
+    fn next(&mut self) -> Option<Row> {
+        while self.remaining_offset > 0 {
+            self.input.next()?;
+            self.remaining_offset -= 1;
+        }
+        if self.remaining_limit == Some(0) {
+            return None;
+        }
+        let row = self.input.next()?;
+        if let Some(limit) = &mut self.remaining_limit {
+            *limit -= 1;
+        }
+        Some(row)
+    }
+}
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::table::{Table, Column};
+    use crate::table::Table;
 
     fn sample_table() -> Table {
-        Table {
-            columns: HashMap::from([
-                ("region".to_string(), Column { data: vec!["East".to_string(), "West".to_string(), "East".to_string()] }),
-                ("sales".to_string(), Column { data: vec!["100".to_string(), "200".to_string(), "300".to_string()] }),
-            ]),
-        }
+        let mut schema = Schema::new();
+        schema.insert("region".to_string(), DataType::Utf8);
+        schema.insert("sales".to_string(), DataType::Int64);
+
+        let columns = HashMap::from([
+            (
+                "region".to_string(),
+                ColumnData::Utf8(vec![Some("East".to_string()), Some("West".to_string()), Some("East".to_string())]),
+            ),
+            (
+                "sales".to_string(),
+                ColumnData::Int64(vec![Some(100), Some(200), Some(300)]),
+            ),
+        ]);
+
+        Table { schema, columns }
     }
 
     #[test]
     fn test_scan_node() {
         let table = sample_table();
-        let scan = ScanNode { table: &table };
+        let scan = ScanNode { table: &table, name: "sample".to_string() };
         let output = scan.execute();
 
-        assert_eq!(output["region"], vec!["East", "West", "East"]);
-        assert_eq!(output["sales"], vec!["100", "200", "300"]);
+        assert_eq!(output.strings("region"), vec!["East", "West", "East"]);
+        assert_eq!(output.strings("sales"), vec!["100", "200", "300"]);
     }
 
     #[test]
     fn test_project_node() {
         let table = sample_table();
-        let scan = PlanNode::Scan(ScanNode { table: &table });
+        let scan = PlanNode::Scan(ScanNode { table: &table, name: "sample".to_string() });
         let project = ProjectNode {
             input: Box::new(scan),
             columns: vec!["sales".to_string()],
         };
         let output = project.execute();
 
-        assert_eq!(output.len(), 1);
-        assert_eq!(output["sales"], vec!["100", "200", "300"]);
+        assert_eq!(output.columns.len(), 1);
+        assert_eq!(output.strings("sales"), vec!["100", "200", "300"]);
     }
 
     #[test]
     fn test_filter_node() {
         let table = sample_table();
-        let scan = PlanNode::Scan(ScanNode { table: &table });
+        let scan = PlanNode::Scan(ScanNode { table: &table, name: "sample".to_string() });
         let filter = FilterNode {
             input: Box::new(scan),
-            predicate: Box::new(|row| row["region"] == "East"),
+            predicate: Expr::BinaryOp {
+                left: Box::new(Expr::Column("region".to_string())),
+                op: Op::Eq,
+                right: Box::new(Expr::Literal(Value::Str("East".to_string()))),
+            },
         };
         let output = filter.execute();
 
-        assert_eq!(output["region"], vec!["East", "East"]);
-        assert_eq!(output["sales"], vec!["100", "300"]);
+        assert_eq!(output.strings("region"), vec!["East", "East"]);
+        assert_eq!(output.strings("sales"), vec!["100", "300"]);
     }
 
     #[test]
     fn test_aggregate_node_sum() {
         let table = sample_table();
-        let scan = PlanNode::Scan(ScanNode { table: &table });
+        let scan = PlanNode::Scan(ScanNode { table: &table, name: "sample".to_string() });
         let aggregate = AggregateNode {
             input: Box::new(scan),
             group_by: vec!["region".to_string()],
             aggregates: vec![("sales".to_string(), AggregateFunction::Sum)],
         };
         let output = aggregate.execute();
+        let regions = output.strings("region");
+        let sales = output.strings("sales");
 
-        assert_eq!(output["region"].len(), 2);
-        assert!(output["region"].contains(&"East".to_string()));
-        assert!(output["region"].contains(&"West".to_string()));
+        assert_eq!(regions.len(), 2);
+        assert!(regions.contains(&"East".to_string()));
+        assert!(regions.contains(&"West".to_string()));
 
-        let east_index = output["region"].iter().position(|r| r == "East").unwrap();
-        let west_index = output["region"].iter().position(|r| r == "West").unwrap();
+        let east_index = regions.iter().position(|r| r == "East").unwrap();
+        let west_index = regions.iter().position(|r| r == "West").unwrap();
 
-        assert_eq!(output["sales"][east_index], "400");
-        assert_eq!(output["sales"][west_index], "200");
+        assert_eq!(sales[east_index], "400");
+        assert_eq!(sales[west_index], "200");
     }
 
     #[test]
     fn test_aggregate_node_count() {
         let table = sample_table();
-        let scan = PlanNode::Scan(ScanNode { table: &table });
+        let scan = PlanNode::Scan(ScanNode { table: &table, name: "sample".to_string() });
         let aggregate = AggregateNode {
             input: Box::new(scan),
             group_by: vec!["region".to_string()],
             aggregates: vec![("sales".to_string(), AggregateFunction::Count)],
         };
         let output = aggregate.execute();
+        let regions = output.strings("region");
+        let sales = output.strings("sales");
+
+        assert_eq!(regions.len(), 2);
+        assert!(regions.contains(&"East".to_string()));
+        assert!(regions.contains(&"West".to_string()));
+
+        let east_index = regions.iter().position(|r| r == "East").unwrap();
+        let west_index = regions.iter().position(|r| r == "West").unwrap();
+
+        assert_eq!(sales[east_index], "2");
+        assert_eq!(sales[west_index], "1");
+    }
+
+    #[test]
+    fn test_aggregate_node_min_max() {
+        let table = sample_table();
+        let scan = PlanNode::Scan(ScanNode { table: &table, name: "sample".to_string() });
+        let aggregate = AggregateNode::try_new(
+            Box::new(scan),
+            vec!["region".to_string()],
+            vec![("sales".to_string(), AggregateFunction::Min)],
+        )
+        .unwrap();
+        let output = aggregate.execute();
+        let regions = output.strings("region");
+        let sales = output.strings("sales");
 
-        assert_eq!(output["region"].len(), 2);
-        assert!(output["region"].contains(&"East".to_string()));
-        assert!(output["region"].contains(&"West".to_string()));
+        let east_index = regions.iter().position(|r| r == "East").unwrap();
+        let west_index = regions.iter().position(|r| r == "West").unwrap();
 
-        let east_index = output["region"].iter().position(|r| r == "East").unwrap();
-        let west_index = output["region"].iter().position(|r| r == "West").unwrap();
+        assert_eq!(sales[east_index], "100");
+        assert_eq!(sales[west_index], "200");
+
+        let scan = PlanNode::Scan(ScanNode { table: &table, name: "sample".to_string() });
+        let aggregate = AggregateNode::try_new(
+            Box::new(scan),
+            vec!["region".to_string()],
+            vec![("sales".to_string(), AggregateFunction::Max)],
+        )
+        .unwrap();
+        let output = aggregate.execute();
+        let regions = output.strings("region");
+        let sales = output.strings("sales");
+
+        let east_index = regions.iter().position(|r| r == "East").unwrap();
+        let west_index = regions.iter().position(|r| r == "West").unwrap();
+
+        assert_eq!(sales[east_index], "300");
+        assert_eq!(sales[west_index], "200");
+    }
+
+    #[test]
+    fn test_aggregate_node_avg() {
+        let table = sample_table();
+        let scan = PlanNode::Scan(ScanNode { table: &table, name: "sample".to_string() });
+        let aggregate = AggregateNode::try_new(
+            Box::new(scan),
+            vec!["region".to_string()],
+            vec![("sales".to_string(), AggregateFunction::Avg)],
+        )
+        .unwrap();
+        let output = aggregate.execute();
+        let regions = output.strings("region");
+        let sales = output.strings("sales");
+
+        let east_index = regions.iter().position(|r| r == "East").unwrap();
+        let west_index = regions.iter().position(|r| r == "West").unwrap();
+
+        assert_eq!(sales[east_index], "200");
+        assert_eq!(sales[west_index], "200");
+    }
+
+    #[test]
+    fn test_aggregate_node_rejects_sum_on_non_numeric_column() {
+        let table = sample_table();
+        let scan = PlanNode::Scan(ScanNode { table: &table, name: "sample".to_string() });
+        let result = AggregateNode::try_new(
+            Box::new(scan),
+            vec![],
+            vec![("region".to_string(), AggregateFunction::Sum)],
+        );
+
+        assert!(result.is_err());
+        assert!(result.err().unwrap().contains("region"));
+    }
+
+    #[test]
+    fn test_explain_renders_indented_plan_tree() {
+        let table = sample_table();
+        let scan = PlanNode::Scan(ScanNode { table: &table, name: "sample".to_string() });
+        let filter = PlanNode::Filter(FilterNode {
+            input: Box::new(scan),
+            predicate: Expr::BinaryOp {
+                left: Box::new(Expr::Column("region".to_string())),
+                op: Op::Eq,
+                right: Box::new(Expr::Literal(Value::Str("East".to_string()))),
+            },
+        });
+        let aggregate = PlanNode::Aggregate(
+            AggregateNode::try_new(
+                Box::new(filter),
+                vec!["region".to_string()],
+                vec![("sales".to_string(), AggregateFunction::Sum)],
+            )
+            .unwrap(),
+        );
+
+        let explained = aggregate.explain(0);
+
+        assert_eq!(
+            explained,
+            "Aggregate: group_by=[region], aggs=[sum(sales)]\n  Filter: region = 'East'\n    Scan: sample"
+        );
+    }
+
+    #[test]
+    fn test_limit_stream_stops_pulling_once_satisfied() {
+        // A stream that panics if pulled more than `max_pulls` times, used
+        // to prove `LimitStream` doesn't drain the rest of its input.
+        struct BoundedStream {
+            schema: Rc<RowSchema>,
+            remaining_rows: usize,
+            max_pulls: usize,
+        }
+
+        impl RowStream for BoundedStream {
+            fn schema(&self) -> &Rc<RowSchema> {
+                &self.schema
+            }
+
+            fn next(&mut self) -> Option<Row> {
+                if self.max_pulls == 0 {
+                    panic!("pulled past the expected number of rows");
+                }
+                self.max_pulls -= 1;
+                if self.remaining_rows == 0 {
+                    return None;
+                }
+                self.remaining_rows -= 1;
+                Some(Row { schema: Rc::clone(&self.schema), values: vec![Value::Int(1)] })
+            }
+        }
+
+        let schema = Rc::new(RowSchema::new(vec![("n".to_string(), DataType::Int64)]));
+        let bounded = BoundedStream { schema: Rc::clone(&schema), remaining_rows: 1_000_000, max_pulls: 2 };
+        let mut limit = LimitStream {
+            input: Box::new(bounded),
+            remaining_offset: 0,
+            remaining_limit: Some(2),
+        };
 
-        assert_eq!(output["sales"][east_index], "2");
-        assert_eq!(output["sales"][west_index], "1");
+        assert!(limit.next().is_some());
+        assert!(limit.next().is_some());
     }
 }
\ No newline at end of file
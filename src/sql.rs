@@ -1,30 +1,179 @@
 use crate::plan::*;
+use crate::types::Value as RtValue;
 use sqlparser::ast::*;
+use sqlparser::ast::Expr;
 use sqlparser::dialect::GenericDialect;
 use sqlparser::parser::Parser;
+use std::collections::HashMap;
 
-pub fn parse_sql_to_plan<'a>(query: &str, table: &'a Table) -> PlanNode<'a> {
+// Recursively converts a `sqlparser` WHERE-clause expression into our
+// own `plan::Expr` tree.
+fn convert_expr(expr: &Expr) -> crate::plan::Expr {
+    match expr {
+        Expr::Identifier(ident) => crate::plan::Expr::Column(ident.value.clone()),
+        Expr::Value(Value::SingleQuotedString(s)) => {
+            crate::plan::Expr::Literal(RtValue::Str(s.clone()))
+        }
+        Expr::Value(Value::Number(n, _)) => {
+            let literal = match n.parse::<i64>() {
+                Ok(i) => RtValue::Int(i),
+                Err(_) => RtValue::Float(n.parse::<f64>().expect("invalid numeric literal")),
+            };
+            crate::plan::Expr::Literal(literal)
+        }
+        Expr::Value(Value::Boolean(b)) => crate::plan::Expr::Literal(RtValue::Bool(*b)),
+        Expr::UnaryOp { op: UnaryOperator::Not, expr } => {
+            crate::plan::Expr::Not(Box::new(convert_expr(expr)))
+        }
+        Expr::BinaryOp { left, op, right } => {
+            let mapped_op = match op {
+                BinaryOperator::Eq => Op::Eq,
+                BinaryOperator::NotEq => Op::NotEq,
+                BinaryOperator::Lt => Op::Lt,
+                BinaryOperator::LtEq => Op::LtEq,
+                BinaryOperator::Gt => Op::Gt,
+                BinaryOperator::GtEq => Op::GtEq,
+                BinaryOperator::And => Op::And,
+                BinaryOperator::Or => Op::Or,
+                _ => panic!("Unsupported binary operator: {:?}", op),
+            };
+            crate::plan::Expr::BinaryOp {
+                left: Box::new(convert_expr(left)),
+                op: mapped_op,
+                right: Box::new(convert_expr(right)),
+            }
+        }
+        _ => panic!("Unsupported WHERE expression: {:?}", expr),
+    }
+}
+
+// Extracts a FROM-clause table's name and its alias (the table name
+// itself, when unaliased).
+fn table_name_and_alias(factor: &TableFactor) -> (String, String) {
+    match factor {
+        TableFactor::Table { name, alias, .. } => {
+            let table_name = name.to_string();
+            let alias_name = alias
+                .as_ref()
+                .map(|a| a.name.value.clone())
+                .unwrap_or_else(|| table_name.clone());
+            (table_name, alias_name)
+        }
+        _ => panic!("Unsupported FROM clause: {:?}", factor),
+    }
+}
+
+// Pulls the `table.column` qualifier and column name out of a JOIN ON
+// operand.
+fn qualified_column(expr: &Expr) -> (String, String) {
+    match expr {
+        Expr::CompoundIdentifier(parts) if parts.len() == 2 => {
+            (parts[0].value.clone(), parts[1].value.clone())
+        }
+        _ => panic!("JOIN ON must reference qualified columns (table.column): {:?}", expr),
+    }
+}
+
+// Matches a JOIN ON equality's two qualified columns against the known
+// left/right aliases and returns (left_on, right_on) in that order.
+fn join_key_columns(on_expr: &Expr, left_alias: &str, right_alias: &str) -> (String, String) {
+    let (left, right) = match on_expr {
+        Expr::BinaryOp { left, op: BinaryOperator::Eq, right } => (&**left, &**right),
+        _ => panic!("Unsupported JOIN ON expression: expected an equality predicate"),
+    };
+
+    let (a_alias, a_col) = qualified_column(left);
+    let (b_alias, b_col) = qualified_column(right);
+
+    if a_alias == left_alias && b_alias == right_alias {
+        (a_col, b_col)
+    } else if a_alias == right_alias && b_alias == left_alias {
+        (b_col, a_col)
+    } else {
+        panic!("JOIN ON column qualifiers don't match the FROM aliases");
+    }
+}
+
+// What parsing a statement produced: either a plan ready to run, or an
+// `EXPLAIN` that has already rendered its plan tree and must not be
+// executed. Keeping these distinct stops a caller from ever running the
+// query an `EXPLAIN` was only supposed to describe.
+pub enum ParsedStatement<'a> {
+    Plan(PlanNode<'a>),
+    Explained(String),
+}
+
+pub fn parse_sql_to_plan<'a>(query: &str, tables: &'a HashMap<String, Table>) -> ParsedStatement<'a> {
     let dialect = GenericDialect {};
     let ast = Parser::parse_sql(&dialect, query).unwrap();
-    let stmt = match &ast[0] {
-        Statement::Query(q) => q,
+
+    match &ast[0] {
+        Statement::Query(q) => ParsedStatement::Plan(build_plan(q, tables)),
+        Statement::Explain { statement, .. } => {
+            let inner = match statement.as_ref() {
+                Statement::Query(q) => q,
+                _ => panic!("EXPLAIN only supports SELECT queries"),
+            };
+            let plan = build_plan(inner, tables);
+            ParsedStatement::Explained(plan.explain(0))
+        }
         _ => panic!("Only SELECT queries supported"),
-    };
+    }
+}
+
+// Extracts an aggregate function call's argument column name, e.g.
+// `sum(sales)` -> `sales`. Used both to name an `AggregateNode`'s output
+// column and to resolve `ORDER BY sum(sales)` to that same column.
+fn function_arg_column(func: &Function) -> String {
+    func.args[0].to_string().replace('\"', "")
+}
+
+fn build_plan<'a>(stmt: &Query, tables: &'a HashMap<String, Table>) -> PlanNode<'a> {
+    // Walk through the parsed query and build a PlanNode chain.
+    // FROM ScanNode, plus any JOINs.
+    let Select { from, .. } = &stmt.body.get_select();
+    let table_with_joins = from.first().expect("FROM clause is required");
+
+    let (first_table, first_alias) = table_name_and_alias(&table_with_joins.relation);
+    let mut plan = PlanNode::Scan(ScanNode {
+        table: &tables[&first_table],
+        name: first_alias.clone(),
+    });
+    let mut current_alias = first_alias;
+
+    for join in &table_with_joins.joins {
+        let (join_table, join_alias) = table_name_and_alias(&join.relation);
 
-    // Walk through the parsed query and build a PlanNode chain
-    // Start with ScanNode
-    let mut plan = PlanNode::Scan(ScanNode { table });
+        let (on_expr, join_type) = match &join.join_operator {
+            JoinOperator::Inner(JoinConstraint::On(on_expr)) => (on_expr, JoinType::Inner),
+            JoinOperator::LeftOuter(JoinConstraint::On(on_expr)) => (on_expr, JoinType::LeftOuter),
+            _ => panic!("Unsupported JOIN type: {:?}", join.join_operator),
+        };
+
+        let (left_on, right_on) = join_key_columns(on_expr, &current_alias, &join_alias);
+
+        plan = PlanNode::Join(JoinNode {
+            left: Box::new(plan),
+            right: Box::new(PlanNode::Scan(ScanNode {
+                table: &tables[&join_table],
+                name: join_alias.clone(),
+            })),
+            left_on,
+            right_on,
+            left_name: current_alias.clone(),
+            right_name: join_alias.clone(),
+            join_type,
+        });
+
+        current_alias = join_alias;
+    }
 
     // WHERE FilterNode
     if let Some(selection) = &stmt.body.get_selection() {
-        if let Expr::BinaryOp { left, op, right } = selection {
-            if let (Expr::Identifier(ident), BinaryOperator::Eq, Expr::Value(Value::SingleQuotedString(val))) = (&**left, op, &**right) {
-                plan = PlanNode::Filter(FilterNode {
-                    input: Box::new(plan),
-                    predicate: Box::new(move |row| row[&ident.value] == *val),
-                });
-            }
-        }
+        plan = PlanNode::Filter(FilterNode {
+            input: Box::new(plan),
+            predicate: convert_expr(selection),
+        });
     }
 
     // GROUP BY AggregateNode
@@ -39,11 +188,13 @@ pub fn parse_sql_to_plan<'a>(query: &str, table: &'a Table) -> PlanNode<'a> {
         if let Select { projection, .. } = &stmt.body.get_select() {
             for item in projection {
                 if let SelectItem::UnnamedExpr(Expr::Function(func)) = item {
-                    let name = func.args[0].to_string().replace('\"', "");
+                    let name = function_arg_column(func);
                     let agg = match func.name.to_string().to_lowercase().as_str() {
                         "sum" => AggregateFunction::Sum,
                         "count" => AggregateFunction::Count,
                         "avg" => AggregateFunction::Avg,
+                        "min" => AggregateFunction::Min,
+                        "max" => AggregateFunction::Max,
                         _ => panic!("Unsupported aggregation"),
                     };
                     aggregates.push((name, agg));
@@ -51,14 +202,62 @@ pub fn parse_sql_to_plan<'a>(query: &str, table: &'a Table) -> PlanNode<'a> {
             }
         }
 
-        plan = PlanNode::Aggregate(AggregateNode {
+        plan = PlanNode::Aggregate(
+            AggregateNode::try_new(Box::new(plan), group_by_cols, aggregates)
+                .unwrap_or_else(|e| panic!("{}", e)),
+        );
+    }
+
+    // ORDER BY SortNode (after GROUP BY, but before the final SELECT
+    // projection: ORDER BY can reference a group/aggregate column the
+    // SELECT list doesn't keep, e.g. `ORDER BY sum(sales)` resolving to
+    // `sales` via `function_arg_column`, same as the GROUP BY aggregate
+    // list above. Projecting first would drop that column before Sort
+    // ever sees it.)
+    if !stmt.order_by.is_empty() {
+        let sort_keys = stmt
+            .order_by
+            .iter()
+            .map(|order_expr| {
+                let col = match &order_expr.expr {
+                    Expr::Identifier(ident) => ident.value.clone(),
+                    Expr::Function(func) => function_arg_column(func),
+                    _ => panic!("Unsupported ORDER BY expression: {:?}", order_expr.expr),
+                };
+                let descending = order_expr.asc == Some(false);
+                (col, descending)
+            })
+            .collect();
+
+        plan = PlanNode::Sort(
+            SortNode::try_new(Box::new(plan), sort_keys).unwrap_or_else(|e| panic!("{}", e)),
+        );
+    }
+
+    // LIMIT / OFFSET LimitNode
+    let limit = stmt.limit.as_ref().map(|expr| match expr {
+        Expr::Value(Value::Number(n, _)) => n.parse::<usize>().expect("invalid LIMIT"),
+        _ => panic!("Unsupported LIMIT expression: {:?}", expr),
+    });
+    let offset = stmt
+        .offset
+        .as_ref()
+        .map(|offset| match &offset.value {
+            Expr::Value(Value::Number(n, _)) => n.parse::<usize>().expect("invalid OFFSET"),
+            _ => panic!("Unsupported OFFSET expression: {:?}", offset.value),
+        })
+        .unwrap_or(0);
+
+    if limit.is_some() || offset > 0 {
+        plan = PlanNode::Limit(LimitNode {
             input: Box::new(plan),
-            group_by: group_by_cols,
-            aggregates,
+            limit,
+            offset,
         });
     }
 
-    // SELECT ProjectNode
+    // SELECT ProjectNode (last, so it only drops columns ORDER BY/LIMIT
+    // no longer need)
     if let Select { projection, .. } = &stmt.body.get_select() {
         let cols: Vec<String> = projection.iter().filter_map(|item| match item {
             SelectItem::UnnamedExpr(Expr::Identifier(ident)) => Some(ident.value.clone()),
@@ -1,41 +1,205 @@
+use crate::types::{DataType, Schema, Value};
 use std::collections::HashMap;
-use std::fs::File;
 use std::error::Error;
 use csv;
 
-pub struct Column<T> {
-  pub name: String,
-  pub data: Vec<T>,
+// Typed, columnar storage for a single `Table` column. `None` entries
+// represent CSV nulls (empty fields).
+#[derive(Debug, Clone)]
+pub enum ColumnData {
+    Int64(Vec<Option<i64>>),
+    Float64(Vec<Option<f64>>),
+    Boolean(Vec<Option<bool>>),
+    Utf8(Vec<Option<String>>),
+}
+
+impl ColumnData {
+    pub fn len(&self) -> usize {
+        match self {
+            ColumnData::Int64(v) => v.len(),
+            ColumnData::Float64(v) => v.len(),
+            ColumnData::Boolean(v) => v.len(),
+            ColumnData::Utf8(v) => v.len(),
+        }
+    }
+
+    pub fn data_type(&self) -> DataType {
+        match self {
+            ColumnData::Int64(_) => DataType::Int64,
+            ColumnData::Float64(_) => DataType::Float64,
+            ColumnData::Boolean(_) => DataType::Boolean,
+            ColumnData::Utf8(_) => DataType::Utf8,
+        }
+    }
+
+    // Renders the value at `idx` the way the string-based execution
+    // pipeline expects: nulls become an empty string.
+    pub fn to_string_at(&self, idx: usize) -> String {
+        match self {
+            ColumnData::Int64(v) => v[idx].map(|x| x.to_string()).unwrap_or_default(),
+            ColumnData::Float64(v) => v[idx].map(|x| x.to_string()).unwrap_or_default(),
+            ColumnData::Boolean(v) => v[idx].map(|x| x.to_string()).unwrap_or_default(),
+            ColumnData::Utf8(v) => v[idx].clone().unwrap_or_default(),
+        }
+    }
+
+    // An empty column of the same type, used to accumulate aggregate
+    // group keys and join output rows.
+    pub fn empty_like(&self) -> ColumnData {
+        match self {
+            ColumnData::Int64(_) => ColumnData::Int64(Vec::new()),
+            ColumnData::Float64(_) => ColumnData::Float64(Vec::new()),
+            ColumnData::Boolean(_) => ColumnData::Boolean(Vec::new()),
+            ColumnData::Utf8(_) => ColumnData::Utf8(Vec::new()),
+        }
+    }
+
+    // Copies the value at `other[idx]` onto the end of `self`. Both sides
+    // must share the same variant.
+    pub fn push_from(&mut self, other: &ColumnData, idx: usize) {
+        match (self, other) {
+            (ColumnData::Int64(dst), ColumnData::Int64(src)) => dst.push(src[idx]),
+            (ColumnData::Float64(dst), ColumnData::Float64(src)) => dst.push(src[idx]),
+            (ColumnData::Boolean(dst), ColumnData::Boolean(src)) => dst.push(src[idx]),
+            (ColumnData::Utf8(dst), ColumnData::Utf8(src)) => dst.push(src[idx].clone()),
+            _ => panic!("cannot copy between columns of different types"),
+        }
+    }
+
+    // Parses `s` into this column's native type and pushes it (or null,
+    // for an empty string / unparseable value).
+    pub fn push_str(&mut self, s: &str) {
+        match self {
+            ColumnData::Int64(v) => v.push(s.parse().ok()),
+            ColumnData::Float64(v) => v.push(s.parse().ok()),
+            ColumnData::Boolean(v) => v.push(s.parse().ok()),
+            ColumnData::Utf8(v) => v.push(if s.is_empty() { None } else { Some(s.to_string()) }),
+        }
+    }
+
+    // Reads the value at `idx` as a native `Value`, for the row-based
+    // (`RowStream`) execution path. A null is surfaced as `Value::Str("")`,
+    // matching `to_string_at`'s null-as-empty-string convention.
+    pub fn value_at(&self, idx: usize) -> Value {
+        match self {
+            ColumnData::Int64(v) => v[idx].map(Value::Int).unwrap_or(Value::Str(String::new())),
+            ColumnData::Float64(v) => v[idx].map(Value::Float).unwrap_or(Value::Str(String::new())),
+            ColumnData::Boolean(v) => v[idx].map(Value::Bool).unwrap_or(Value::Str(String::new())),
+            ColumnData::Utf8(v) => v[idx].clone().map(Value::Str).unwrap_or(Value::Str(String::new())),
+        }
+    }
+
+    // Appends a `Value` produced by the row-based execution path. A
+    // `Value` whose variant doesn't match this column (including the
+    // `Value::Str("")` null sentinel from `value_at`) is stored as null.
+    pub fn push_value(&mut self, value: &Value) {
+        match (self, value) {
+            (ColumnData::Int64(v), Value::Int(i)) => v.push(Some(*i)),
+            (ColumnData::Float64(v), Value::Float(f)) => v.push(Some(*f)),
+            (ColumnData::Boolean(v), Value::Bool(b)) => v.push(Some(*b)),
+            (ColumnData::Utf8(v), Value::Str(s)) if !s.is_empty() => v.push(Some(s.clone())),
+            (ColumnData::Int64(v), _) => v.push(None),
+            (ColumnData::Float64(v), _) => v.push(None),
+            (ColumnData::Boolean(v), _) => v.push(None),
+            (ColumnData::Utf8(v), _) => v.push(None),
+        }
+    }
+
+    // Pushes a null onto this column, e.g. for unmatched LEFT OUTER JOIN rows.
+    pub fn push_null(&mut self) {
+        match self {
+            ColumnData::Int64(v) => v.push(None),
+            ColumnData::Float64(v) => v.push(None),
+            ColumnData::Boolean(v) => v.push(None),
+            ColumnData::Utf8(v) => v.push(None),
+        }
+    }
+
+    // Reorders (and/or slices) this column according to `indices`, e.g.
+    // for a sort permutation.
+    pub fn gather(&self, indices: &[usize]) -> ColumnData {
+        match self {
+            ColumnData::Int64(v) => ColumnData::Int64(indices.iter().map(|&i| v[i]).collect()),
+            ColumnData::Float64(v) => ColumnData::Float64(indices.iter().map(|&i| v[i]).collect()),
+            ColumnData::Boolean(v) => ColumnData::Boolean(indices.iter().map(|&i| v[i]).collect()),
+            ColumnData::Utf8(v) => ColumnData::Utf8(indices.iter().map(|&i| v[i].clone()).collect()),
+        }
+    }
 }
 
 pub struct Table {
-  pub columns: HashMap<String, Column<String>>
+    pub schema: Schema,
+    pub columns: HashMap<String, ColumnData>,
 }
 
 impl Table {
-  pub fn load_csv(path: &str) -> Result<Self, Box<dyn Error>> {
-    let mut reader = csv::Reader::from_path(path)?;
-    let headers = reader.headers()?.clone();
+    pub fn num_rows(&self) -> usize {
+        self.columns.values().next().map_or(0, |c| c.len())
+    }
+
+    pub fn load_csv(path: &str) -> Result<Self, Box<dyn Error>> {
+        let mut reader = csv::Reader::from_path(path)?;
+        let headers = reader.headers()?.clone();
+
+        let mut raw: HashMap<String, Vec<String>> = HashMap::new();
+        for header in headers.iter() {
+            raw.insert(header.to_string(), Vec::new());
+        }
 
-    let mut columns: HashMap<String, Column<String>> = HashMap::new();
+        for record in reader.records() {
+            let record = record?;
+            for (i, field) in record.iter().enumerate() {
+                let header = &headers[i];
+                raw.get_mut(header).unwrap().push(field.to_string());
+            }
+        }
 
-    for header in headers.iter() {
-      columns.insert(header.to_string(), Column {
-        name: header.to_string(),
-        data: Vec::new(),
-      });
+        let mut schema = Schema::new();
+        let mut columns = HashMap::new();
+
+        for (name, values) in raw {
+            let data_type = infer_data_type(&values);
+            let mut column = empty_column(data_type);
+            for value in &values {
+                column.push_str(value);
+            }
+
+            schema.insert(name.clone(), data_type);
+            columns.insert(name, column);
+        }
+
+        Ok(Table { schema, columns })
     }
+}
 
-    for record in reader.records() {
-      let record = record?;
-      for  (i, field) in record.iter().enumerate() {
-        let header = &headers[i];
-        columns.get_mut(header).unwrap().data.push(field.to_string());
-      }
+// Scans a column's raw string values, promoting Int64 -> Float64 -> Utf8
+// as soon as a value fails to parse at the current level. Empty values
+// are nulls and don't affect the inferred type.
+fn infer_data_type(values: &[String]) -> DataType {
+    let mut inferred = DataType::Int64;
+    for value in values {
+        if value.is_empty() || inferred == DataType::Utf8 {
+            continue;
+        }
+        if inferred == DataType::Int64 && value.parse::<i64>().is_ok() {
+            continue;
+        }
+        inferred = if value.parse::<f64>().is_ok() {
+            DataType::Float64
+        } else {
+            DataType::Utf8
+        };
     }
+    inferred
+}
 
-    Ok(Table { columns })
-  }
+fn empty_column(data_type: DataType) -> ColumnData {
+    match data_type {
+        DataType::Int64 => ColumnData::Int64(Vec::new()),
+        DataType::Float64 => ColumnData::Float64(Vec::new()),
+        DataType::Boolean => ColumnData::Boolean(Vec::new()),
+        DataType::Utf8 => ColumnData::Utf8(Vec::new()),
+    }
 }
 
 #[cfg(test)]
@@ -46,8 +210,9 @@ mod tests {
     fn test_load_csv() {
         let table = Table::load_csv("data/sample.csv").expect("CSV failed to load");
 
-        assert!(table.columns.contains_key("region"));
-        assert_eq!(table.columns["region"].data[0], "East");
-        assert_eq!(table.columns["sales"].data[1], "200");
+        assert_eq!(table.schema["region"], DataType::Utf8);
+        assert_eq!(table.schema["sales"], DataType::Int64);
+        assert_eq!(table.columns["region"].to_string_at(0), "East");
+        assert_eq!(table.columns["sales"].to_string_at(1), "200");
     }
-}
\ No newline at end of file
+}
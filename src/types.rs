@@ -0,0 +1,42 @@
+// Runtime value representation shared across planning and execution.
+
+use std::collections::HashMap;
+
+// Native storage/runtime type of a column, inferred from its CSV values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DataType {
+    Int64,
+    Float64,
+    Boolean,
+    Utf8,
+}
+
+// Maps column name to its inferred `DataType`, threaded alongside a
+// `DataChunk` so nodes can operate on native types instead of strings.
+pub type Schema = HashMap<String, DataType>;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    Str(String),
+    Int(i64),
+    Float(f64),
+    Bool(bool),
+}
+
+impl Value {
+    pub fn as_bool(&self) -> bool {
+        match self {
+            Value::Bool(b) => *b,
+            other => panic!("expected a boolean value, got {:?}", other),
+        }
+    }
+
+    pub fn to_comparable_string(&self) -> String {
+        match self {
+            Value::Str(s) => s.clone(),
+            Value::Int(i) => i.to_string(),
+            Value::Float(f) => f.to_string(),
+            Value::Bool(b) => b.to_string(),
+        }
+    }
+}
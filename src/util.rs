@@ -0,0 +1,12 @@
+// Shared comparison helpers used by filters, sorts, and aggregates.
+
+use std::cmp::Ordering;
+
+/// Compares two string-encoded values numerically when both parse as f64,
+/// falling back to lexical comparison otherwise.
+pub fn compare_str(a: &str, b: &str) -> Ordering {
+    match (a.parse::<f64>(), b.parse::<f64>()) {
+        (Ok(x), Ok(y)) => x.partial_cmp(&y).unwrap_or(Ordering::Equal),
+        _ => a.cmp(b),
+    }
+}